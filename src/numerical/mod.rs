@@ -0,0 +1,3 @@
+//! Numerical algorithms: root finding, interpolation, integration, optimization & ODEs.
+
+pub mod ode;
@@ -0,0 +1,500 @@
+//! Ordinary differential equation solvers.
+//!
+//! This module provides fixed-step explicit integrators (`Euler`, `RK4`) and adaptive,
+//! embedded Runge-Kutta integrators (`RKF45`, `DOPRI5`) that choose their own step
+//! size from a local error estimate.
+//!
+//! ## Example
+//!
+//! ```
+//! use peroxide::numerical::ode::*;
+//!
+//! struct Exp;
+//!
+//! impl ODEProblem for Exp {
+//!     fn rhs(&self, _t: f64, y: &[f64], dy: &mut [f64]) -> Result<(), ODEError> {
+//!         dy[0] = y[0];
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let solver = RKF45::new(1e-8, 1e-8, 1e-2, 1e-6, (0.1, 4.0));
+//! let solution = solver.solve(&Exp, (0.0, 1.0), &[1.0]).unwrap();
+//! assert!((solution.y.last().unwrap()[0] - std::f64::consts::E).abs() < 1e-4);
+//! ```
+
+/// Errors that can occur while integrating an [`ODEProblem`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ODEError {
+    /// The right-hand side could not be evaluated (e.g. produced a NaN).
+    InvalidRHS,
+    /// The step size was reduced below `h_min` while still failing the error test.
+    StepSizeTooSmall,
+    /// The solver exceeded the maximum number of steps without reaching `t_end`.
+    MaxStepsExceeded,
+}
+
+impl std::fmt::Display for ODEError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ODEError::InvalidRHS => write!(f, "ODE right-hand side evaluated to a non-finite value"),
+            ODEError::StepSizeTooSmall => write!(f, "adaptive step size fell below the configured minimum"),
+            ODEError::MaxStepsExceeded => write!(f, "maximum number of steps exceeded before reaching t_end"),
+        }
+    }
+}
+
+impl std::error::Error for ODEError {}
+
+/// A first-order initial value problem `dy/dt = f(t, y)`.
+///
+/// Implement this for your system and hand it to any of the solvers in this module.
+pub trait ODEProblem {
+    /// Evaluate the right-hand side `f(t, y)`, writing the result into `dy`.
+    fn rhs(&self, t: f64, y: &[f64], dy: &mut [f64]) -> Result<(), ODEError>;
+}
+
+/// The accepted `(t, y)` pairs produced by integrating an [`ODEProblem`].
+///
+/// `y[i]` is the state vector at time `t[i]`. The layout is row-major by time step,
+/// matching the column layout `DataFrame` expects when building one from a `Vec<Vec<f64>>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OdeSolution {
+    pub t: Vec<f64>,
+    pub y: Vec<Vec<f64>>,
+}
+
+/// Fixed-step explicit Euler method.
+pub struct Euler {
+    pub step_size: f64,
+}
+
+impl Euler {
+    pub fn new(step_size: f64) -> Self {
+        Self { step_size }
+    }
+
+    pub fn solve(
+        &self,
+        problem: &impl ODEProblem,
+        (t_start, t_end): (f64, f64),
+        y0: &[f64],
+    ) -> Result<OdeSolution, ODEError> {
+        let n = y0.len();
+        let mut t = t_start;
+        let mut y = y0.to_vec();
+        let mut dy = vec![0f64; n];
+
+        let mut ts = vec![t];
+        let mut ys = vec![y.clone()];
+
+        while t < t_end {
+            let h = self.step_size.min(t_end - t);
+            problem.rhs(t, &y, &mut dy)?;
+            for i in 0..n {
+                y[i] += h * dy[i];
+            }
+            t += h;
+            ts.push(t);
+            ys.push(y.clone());
+        }
+
+        Ok(OdeSolution { t: ts, y: ys })
+    }
+}
+
+/// Fixed-step classical 4th order Runge-Kutta method.
+pub struct RK4 {
+    pub step_size: f64,
+}
+
+impl RK4 {
+    pub fn new(step_size: f64) -> Self {
+        Self { step_size }
+    }
+
+    pub fn solve(
+        &self,
+        problem: &impl ODEProblem,
+        (t_start, t_end): (f64, f64),
+        y0: &[f64],
+    ) -> Result<OdeSolution, ODEError> {
+        let mut t = t_start;
+        let mut y = y0.to_vec();
+
+        let mut ts = vec![t];
+        let mut ys = vec![y.clone()];
+
+        while t < t_end {
+            let h = self.step_size.min(t_end - t);
+            let y_next = rk4_step(problem, t, &y, h)?;
+            y = y_next;
+            t += h;
+            ts.push(t);
+            ys.push(y.clone());
+        }
+
+        Ok(OdeSolution { t: ts, y: ys })
+    }
+}
+
+fn rk4_step(problem: &impl ODEProblem, t: f64, y: &[f64], h: f64) -> Result<Vec<f64>, ODEError> {
+    let n = y.len();
+    let mut k1 = vec![0f64; n];
+    let mut k2 = vec![0f64; n];
+    let mut k3 = vec![0f64; n];
+    let mut k4 = vec![0f64; n];
+    let mut tmp = vec![0f64; n];
+
+    problem.rhs(t, y, &mut k1)?;
+
+    for i in 0..n {
+        tmp[i] = y[i] + 0.5 * h * k1[i];
+    }
+    problem.rhs(t + 0.5 * h, &tmp, &mut k2)?;
+
+    for i in 0..n {
+        tmp[i] = y[i] + 0.5 * h * k2[i];
+    }
+    problem.rhs(t + 0.5 * h, &tmp, &mut k3)?;
+
+    for i in 0..n {
+        tmp[i] = y[i] + h * k3[i];
+    }
+    problem.rhs(t + h, &tmp, &mut k4)?;
+
+    let mut y_next = vec![0f64; n];
+    for i in 0..n {
+        y_next[i] = y[i] + (h / 6.0) * (k1[i] + 2.0 * k2[i] + 2.0 * k3[i] + k4[i]);
+    }
+
+    if y_next.iter().any(|v| !v.is_finite()) {
+        return Err(ODEError::InvalidRHS);
+    }
+
+    Ok(y_next)
+}
+
+/// Butcher tableau for an embedded Runge-Kutta pair: a lower-order solution `y`
+/// and a higher-order solution `y_hat` computed from the same stage evaluations,
+/// whose difference estimates the local truncation error.
+struct EmbeddedTableau {
+    /// Stage time fractions `c_i`.
+    c: &'static [f64],
+    /// Stage coefficients `a_ij`, row `i` has `i` entries.
+    a: &'static [&'static [f64]],
+    /// Weights for the lower-order solution.
+    b_low: &'static [f64],
+    /// Weights for the higher-order solution.
+    b_high: &'static [f64],
+    /// Order of `b_low`, used in the step-size update exponent `1 / (p + 1)`.
+    order: u32,
+}
+
+/// Runge-Kutta-Fehlberg 4(5) coefficients.
+const RKF45_TABLEAU: EmbeddedTableau = EmbeddedTableau {
+    c: &[0.0, 1.0 / 4.0, 3.0 / 8.0, 12.0 / 13.0, 1.0, 1.0 / 2.0],
+    a: &[
+        &[],
+        &[1.0 / 4.0],
+        &[3.0 / 32.0, 9.0 / 32.0],
+        &[1932.0 / 2197.0, -7200.0 / 2197.0, 7296.0 / 2197.0],
+        &[439.0 / 216.0, -8.0, 3680.0 / 513.0, -845.0 / 4104.0],
+        &[-8.0 / 27.0, 2.0, -3544.0 / 2565.0, 1859.0 / 4104.0, -11.0 / 40.0],
+    ],
+    b_low: &[25.0 / 216.0, 0.0, 1408.0 / 2565.0, 2197.0 / 4104.0, -1.0 / 5.0, 0.0],
+    b_high: &[
+        16.0 / 135.0,
+        0.0,
+        6656.0 / 12825.0,
+        28561.0 / 56430.0,
+        -9.0 / 50.0,
+        2.0 / 55.0,
+    ],
+    order: 4,
+};
+
+/// Dormand-Prince 5(4) coefficients (the pair used by MATLAB's `ode45`).
+const DOPRI5_TABLEAU: EmbeddedTableau = EmbeddedTableau {
+    c: &[0.0, 1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0],
+    a: &[
+        &[],
+        &[1.0 / 5.0],
+        &[3.0 / 40.0, 9.0 / 40.0],
+        &[44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0],
+        &[19372.0 / 6561.0, -25360.0 / 2187.0, 64448.0 / 6561.0, -212.0 / 729.0],
+        &[9017.0 / 3168.0, -355.0 / 33.0, 46732.0 / 5247.0, 49.0 / 176.0, -5103.0 / 18656.0],
+        &[35.0 / 384.0, 0.0, 500.0 / 1113.0, 125.0 / 192.0, -2187.0 / 6784.0, 11.0 / 84.0],
+    ],
+    b_low: &[5179.0 / 57600.0, 0.0, 7571.0 / 16695.0, 393.0 / 640.0, -92097.0 / 339200.0, 187.0 / 2100.0, 1.0 / 40.0],
+    b_high: &[35.0 / 384.0, 0.0, 500.0 / 1113.0, 125.0 / 192.0, -2187.0 / 6784.0, 11.0 / 84.0, 0.0],
+    order: 4,
+};
+
+/// Shared driver for an adaptive embedded Runge-Kutta integrator.
+///
+/// Constructed through [`RKF45`] or [`DOPRI5`]; both simply plug a different
+/// [`EmbeddedTableau`] into this driver.
+struct AdaptiveRK {
+    tableau: EmbeddedTableau,
+    abs_tol: f64,
+    rel_tol: f64,
+    h_init: f64,
+    h_max: f64,
+    h_min: f64,
+    max_steps: usize,
+    /// Multiplier applied to `(1 / err)^(1 / (p + 1))` before clamping.
+    safety: f64,
+    /// Step is shrunk/grown by a factor within `[shrink_limit, grow_limit]` per attempt.
+    shrink_limit: f64,
+    grow_limit: f64,
+}
+
+impl AdaptiveRK {
+    fn solve(
+        &self,
+        problem: &impl ODEProblem,
+        (t_start, t_end): (f64, f64),
+        y0: &[f64],
+    ) -> Result<OdeSolution, ODEError> {
+        let n = y0.len();
+        let stages = self.tableau.c.len();
+
+        let mut t = t_start;
+        let mut y = y0.to_vec();
+        let mut h = self.h_init.min(self.h_max).min(t_end - t_start);
+
+        let mut ts = vec![t];
+        let mut ys = vec![y.clone()];
+
+        let mut steps = 0usize;
+        while t < t_end {
+            if steps >= self.max_steps {
+                return Err(ODEError::MaxStepsExceeded);
+            }
+            steps += 1;
+
+            let h_step = h.min(t_end - t);
+            let mut k: Vec<Vec<f64>> = Vec::with_capacity(stages);
+            let mut stage = vec![0f64; n];
+
+            for s in 0..stages {
+                for i in 0..n {
+                    stage[i] = y[i]
+                        + h_step
+                            * self.tableau.a[s]
+                                .iter()
+                                .zip(k.iter())
+                                .map(|(a_sj, k_j): (&f64, &Vec<f64>)| a_sj * k_j[i])
+                                .sum::<f64>();
+                }
+                let mut dy = vec![0f64; n];
+                problem.rhs(t + self.tableau.c[s] * h_step, &stage, &mut dy)?;
+                if dy.iter().any(|v| !v.is_finite()) {
+                    return Err(ODEError::InvalidRHS);
+                }
+                k.push(dy);
+            }
+
+            let mut y_low = vec![0f64; n];
+            let mut y_high = vec![0f64; n];
+            for i in 0..n {
+                y_low[i] = y[i]
+                    + h_step * self.tableau.b_low.iter().zip(&k).map(|(b, k_j)| b * k_j[i]).sum::<f64>();
+                y_high[i] = y[i]
+                    + h_step * self.tableau.b_high.iter().zip(&k).map(|(b, k_j)| b * k_j[i]).sum::<f64>();
+            }
+
+            let err = (0..n)
+                .map(|i| {
+                    let scale = self.abs_tol + self.rel_tol * y[i].abs().max(y_high[i].abs());
+                    ((y_high[i] - y_low[i]) / scale).powi(2)
+                })
+                .sum::<f64>()
+                .sqrt()
+                / (n as f64).sqrt();
+
+            let order = self.tableau.order as f64;
+            let factor = if err == 0.0 {
+                self.grow_limit
+            } else {
+                (self.safety * (1.0 / err).powf(1.0 / (order + 1.0)))
+                    .clamp(self.shrink_limit, self.grow_limit)
+            };
+
+            if err <= 1.0 {
+                t += h_step;
+                y = y_high;
+                ts.push(t);
+                ys.push(y.clone());
+            }
+
+            h = (h_step * factor).min(self.h_max);
+            if err > 1.0 && h < self.h_min {
+                return Err(ODEError::StepSizeTooSmall);
+            }
+        }
+
+        Ok(OdeSolution { t: ts, y: ys })
+    }
+}
+
+/// Adaptive Runge-Kutta-Fehlberg 4(5) integrator.
+///
+/// Computes a 4th and 5th order solution from the same stage evaluations each step,
+/// rescaling the step size from their difference:
+/// `h_new = h * safety * (1 / err)^(1 / (p + 1))`, clamped to `[shrink_limit, grow_limit]`.
+/// A step is rejected (and retried with the shrunk `h`) whenever the estimated error
+/// exceeds the combined absolute/relative tolerance.
+pub struct RKF45(AdaptiveRK);
+
+impl RKF45 {
+    /// * `abs_tol`, `rel_tol` - combined per-component error tolerance `abs_tol + rel_tol * |y|`.
+    /// * `h_min`, `h_max` - bounds on the step size; a step shrunk below `h_min` is an error.
+    /// * `h_init` - initial step size to attempt.
+    /// * `step_limits` - `(shrink_limit, grow_limit)` clamp applied to the per-step rescale factor.
+    pub fn new(abs_tol: f64, rel_tol: f64, h_init: f64, h_min: f64, step_limits: (f64, f64)) -> Self {
+        Self(AdaptiveRK {
+            tableau: RKF45_TABLEAU,
+            abs_tol,
+            rel_tol,
+            h_init,
+            h_max: h_init.max(1.0),
+            h_min,
+            max_steps: 100_000,
+            safety: 0.9,
+            shrink_limit: step_limits.0,
+            grow_limit: step_limits.1,
+        })
+    }
+
+    /// Caps the largest step size the integrator is allowed to take.
+    pub fn with_max_step(mut self, h_max: f64) -> Self {
+        self.0.h_max = h_max;
+        self
+    }
+
+    /// Caps the number of accepted+rejected steps before giving up.
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.0.max_steps = max_steps;
+        self
+    }
+
+    pub fn solve(
+        &self,
+        problem: &impl ODEProblem,
+        span: (f64, f64),
+        y0: &[f64],
+    ) -> Result<OdeSolution, ODEError> {
+        self.0.solve(problem, span, y0)
+    }
+}
+
+/// Adaptive Dormand-Prince 5(4) integrator (the pair MATLAB's `ode45` uses).
+///
+/// Same step-control scheme as [`RKF45`] but with the Dormand-Prince tableau, which
+/// tends to need fewer rejected steps in practice. All seven stages are evaluated on
+/// every step; the last stage of one step is not reused as the first stage of the next.
+pub struct DOPRI5(AdaptiveRK);
+
+impl DOPRI5 {
+    pub fn new(abs_tol: f64, rel_tol: f64, h_init: f64, h_min: f64, step_limits: (f64, f64)) -> Self {
+        Self(AdaptiveRK {
+            tableau: DOPRI5_TABLEAU,
+            abs_tol,
+            rel_tol,
+            h_init,
+            h_max: h_init.max(1.0),
+            h_min,
+            max_steps: 100_000,
+            safety: 0.9,
+            shrink_limit: step_limits.0,
+            grow_limit: step_limits.1,
+        })
+    }
+
+    pub fn with_max_step(mut self, h_max: f64) -> Self {
+        self.0.h_max = h_max;
+        self
+    }
+
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.0.max_steps = max_steps;
+        self
+    }
+
+    pub fn solve(
+        &self,
+        problem: &impl ODEProblem,
+        span: (f64, f64),
+        y0: &[f64],
+    ) -> Result<OdeSolution, ODEError> {
+        self.0.solve(problem, span, y0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Exponential;
+
+    impl ODEProblem for Exponential {
+        fn rhs(&self, _t: f64, y: &[f64], dy: &mut [f64]) -> Result<(), ODEError> {
+            dy[0] = y[0];
+            Ok(())
+        }
+    }
+
+    struct HarmonicOscillator;
+
+    impl ODEProblem for HarmonicOscillator {
+        fn rhs(&self, _t: f64, y: &[f64], dy: &mut [f64]) -> Result<(), ODEError> {
+            dy[0] = y[1];
+            dy[1] = -y[0];
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn rk4_matches_exponential() {
+        let solver = RK4::new(1e-3);
+        let sol = solver.solve(&Exponential, (0.0, 1.0), &[1.0]).unwrap();
+        let y_end = sol.y.last().unwrap()[0];
+        assert!((y_end - std::f64::consts::E).abs() < 1e-6);
+    }
+
+    #[test]
+    fn rkf45_matches_exponential_within_tolerance() {
+        let solver = RKF45::new(1e-10, 1e-10, 1e-2, 1e-8, (0.1, 5.0));
+        let sol = solver.solve(&Exponential, (0.0, 1.0), &[1.0]).unwrap();
+        let y_end = sol.y.last().unwrap()[0];
+        assert!((y_end - std::f64::consts::E).abs() < 1e-6);
+    }
+
+    #[test]
+    fn dopri5_conserves_harmonic_energy() {
+        let solver = DOPRI5::new(1e-10, 1e-10, 1e-2, 1e-8, (0.1, 5.0));
+        let sol = solver
+            .solve(&HarmonicOscillator, (0.0, 10.0), &[1.0, 0.0])
+            .unwrap();
+        let last = sol.y.last().unwrap();
+        let energy = last[0] * last[0] + last[1] * last[1];
+        assert!((energy - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn rejects_step_size_collapsing_to_zero() {
+        struct Blowup;
+        impl ODEProblem for Blowup {
+            fn rhs(&self, _t: f64, y: &[f64], dy: &mut [f64]) -> Result<(), ODEError> {
+                dy[0] = y[0] * y[0] * 1e6;
+                Ok(())
+            }
+        }
+
+        let solver = RKF45::new(1e-12, 1e-12, 1e-3, 1e-6, (0.1, 2.0));
+        let result = solver.solve(&Blowup, (0.0, 1.0), &[1.0]);
+        assert!(matches!(result, Err(ODEError::StepSizeTooSmall)));
+    }
+}
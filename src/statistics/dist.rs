@@ -0,0 +1,272 @@
+//! Probability distributions, sampling and density evaluation.
+//!
+//! This module currently focuses on the multivariate distributions needed for
+//! Bayesian workflows: [`MultivariateNormal`] (built on [`Matrix`]), [`Dirichlet`]
+//! and [`Multinomial`].
+
+use crate::structure::matrix::Matrix;
+use rand::Rng;
+
+/// A multivariate Gaussian `N(mean, cov)`.
+///
+/// Sampling and density evaluation both go through the Cholesky factor `L` of `cov`
+/// (`cov = L L^T`) rather than an explicit inverse: sampling draws `z ~ N(0, I)` and
+/// returns `mean + L z`, while the log-density solves `L y = x - mean` by forward
+/// substitution and uses `log|cov| = 2 sum(ln(L_ii))` for the normalizing constant.
+#[cfg(feature = "O3")]
+pub struct MultivariateNormal {
+    mean: Vec<f64>,
+    cov: Matrix,
+    chol: Matrix,
+    log_det: f64,
+}
+
+#[cfg(feature = "O3")]
+impl MultivariateNormal {
+    /// # Panics
+    /// Panics if `mean.len() != cov.row()` or `cov` is not square.
+    pub fn new(mean: Vec<f64>, cov: Matrix) -> Self {
+        assert_eq!(cov.row(), cov.col(), "covariance matrix must be square");
+        assert_eq!(mean.len(), cov.row(), "mean length must match covariance dimension");
+
+        let chol = cov.cholesky();
+        let log_det = 2.0 * (0..chol.row()).map(|i| chol[(i, i)].ln()).sum::<f64>();
+        Self { mean, cov, chol, log_det }
+    }
+
+    pub fn dim(&self) -> usize {
+        self.mean.len()
+    }
+
+    pub fn mean(&self) -> &[f64] {
+        &self.mean
+    }
+
+    pub fn cov(&self) -> &Matrix {
+        &self.cov
+    }
+
+    /// Draw `x = mean + L z` with `z ~ N(0, I)`.
+    pub fn sample(&self, rng: &mut impl Rng) -> Vec<f64> {
+        let n = self.dim();
+        let z: Vec<f64> = (0..n).map(|_| standard_normal(rng)).collect();
+
+        let mut x = self.mean.clone();
+        for i in 0..n {
+            let mut acc = 0.0;
+            for j in 0..=i {
+                acc += self.chol[(i, j)] * z[j];
+            }
+            x[i] += acc;
+        }
+        x
+    }
+
+    /// Log-density at `x`.
+    pub fn logpdf(&self, x: &[f64]) -> f64 {
+        let n = self.dim();
+        let diff: Vec<f64> = (0..n).map(|i| x[i] - self.mean[i]).collect();
+
+        // Forward substitution: solve L y = diff.
+        let mut y = vec![0.0; n];
+        for i in 0..n {
+            let mut s = diff[i];
+            for k in 0..i {
+                s -= self.chol[(i, k)] * y[k];
+            }
+            y[i] = s / self.chol[(i, i)];
+        }
+
+        let quadratic_form: f64 = y.iter().map(|v| v * v).sum();
+        -0.5 * (quadratic_form + self.log_det + n as f64 * (2.0 * std::f64::consts::PI).ln())
+    }
+}
+
+/// A Dirichlet distribution over the probability simplex, parameterized by
+/// concentration parameters `alpha`.
+pub struct Dirichlet {
+    alpha: Vec<f64>,
+}
+
+impl Dirichlet {
+    /// # Panics
+    /// Panics if any entry of `alpha` is not strictly positive.
+    pub fn new(alpha: Vec<f64>) -> Self {
+        assert!(alpha.iter().all(|&a| a > 0.0), "Dirichlet concentration parameters must be positive");
+        Self { alpha }
+    }
+
+    /// Draw a point on the simplex by sampling independent Gammas and normalizing.
+    pub fn sample(&self, rng: &mut impl Rng) -> Vec<f64> {
+        let gammas: Vec<f64> = self.alpha.iter().map(|&a| sample_gamma(rng, a)).collect();
+        let sum: f64 = gammas.iter().sum();
+        gammas.into_iter().map(|g| g / sum).collect()
+    }
+
+    pub fn logpdf(&self, x: &[f64]) -> f64 {
+        assert_eq!(x.len(), self.alpha.len(), "x must have one component per concentration parameter");
+        let alpha_sum: f64 = self.alpha.iter().sum();
+        let ln_beta = self.alpha.iter().map(|&a| puruspe::ln_gamma(a)).sum::<f64>() - puruspe::ln_gamma(alpha_sum);
+        self.alpha.iter().zip(x).map(|(&a, &xi)| (a - 1.0) * xi.ln()).sum::<f64>() - ln_beta
+    }
+}
+
+/// A multinomial distribution: `n` draws over categories with probabilities `p`.
+pub struct Multinomial {
+    n: usize,
+    p: Vec<f64>,
+}
+
+impl Multinomial {
+    /// # Panics
+    /// Panics if any entry of `p` is negative, or if `p` does not sum to `1` (within `1e-9`).
+    pub fn new(n: usize, p: Vec<f64>) -> Self {
+        assert!(p.iter().all(|&pi| pi >= 0.0), "probabilities must be non-negative");
+        assert!((p.iter().sum::<f64>() - 1.0).abs() < 1e-9, "probabilities must sum to 1");
+        Self { n, p }
+    }
+
+    /// Draw category counts via sequential conditional binomials.
+    pub fn sample(&self, rng: &mut impl Rng) -> Vec<usize> {
+        let mut counts = vec![0usize; self.p.len()];
+        let mut remaining = self.n;
+        let mut remaining_mass = 1.0;
+
+        for i in 0..self.p.len() - 1 {
+            if remaining == 0 {
+                break;
+            }
+            let pi = (self.p[i] / remaining_mass).clamp(0.0, 1.0);
+            let draw = sample_binomial(rng, remaining, pi);
+            counts[i] = draw;
+            remaining -= draw;
+            remaining_mass -= self.p[i];
+        }
+        *counts.last_mut().unwrap() += remaining;
+
+        counts
+    }
+
+    pub fn pmf(&self, counts: &[usize]) -> f64 {
+        assert_eq!(counts.len(), self.p.len(), "counts must have one entry per category");
+        assert_eq!(counts.iter().sum::<usize>(), self.n, "counts must sum to n");
+
+        let log_coef = ln_factorial(self.n) - counts.iter().map(|&c| ln_factorial(c)).sum::<f64>();
+        let log_prob: f64 = counts
+            .iter()
+            .zip(&self.p)
+            .filter(|(&c, _)| c > 0)
+            .map(|(&c, &pi)| c as f64 * pi.ln())
+            .sum();
+        (log_coef + log_prob).exp()
+    }
+}
+
+fn ln_factorial(n: usize) -> f64 {
+    puruspe::ln_gamma(n as f64 + 1.0)
+}
+
+/// Standard normal sample via the Box-Muller transform.
+fn standard_normal(rng: &mut impl Rng) -> f64 {
+    let u1: f64 = rng.gen_range(f64::EPSILON..1.0);
+    let u2: f64 = rng.gen_range(0.0..1.0);
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+/// Marsaglia-Tsang sampling for `Gamma(shape, scale = 1)`.
+fn sample_gamma(rng: &mut impl Rng, shape: f64) -> f64 {
+    if shape < 1.0 {
+        let u: f64 = rng.gen_range(0.0..1.0);
+        return sample_gamma(rng, 1.0 + shape) * u.powf(1.0 / shape);
+    }
+
+    let d = shape - 1.0 / 3.0;
+    let c = 1.0 / (9.0 * d).sqrt();
+
+    loop {
+        let x = standard_normal(rng);
+        let v = (1.0 + c * x).powi(3);
+        if v <= 0.0 {
+            continue;
+        }
+        let u: f64 = rng.gen_range(0.0..1.0);
+        if u < 1.0 - 0.0331 * x.powi(4) || u.ln() < 0.5 * x * x + d * (1.0 - v + v.ln()) {
+            return d * v;
+        }
+    }
+}
+
+/// Binomial sample via `n` independent Bernoulli draws; fine for the small `n`
+/// multinomial sampling needs it for.
+fn sample_binomial(rng: &mut impl Rng, n: usize, p: f64) -> usize {
+    (0..n).filter(|_| rng.gen_range(0.0..1.0) < p).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "O3")]
+    #[test]
+    fn mvn_logpdf_peaks_at_the_mean() {
+        let cov = Matrix::new(vec![1.0, 0.0, 0.0, 1.0], 2, 2, crate::structure::matrix::Shape::Row);
+        let dist = MultivariateNormal::new(vec![0.0, 0.0], cov);
+        assert!(dist.logpdf(&[0.0, 0.0]) > dist.logpdf(&[1.0, 1.0]));
+    }
+
+    #[cfg(feature = "O3")]
+    #[test]
+    fn mvn_sample_matches_manual_cholesky_transform() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let cov = Matrix::new(vec![4.0, 2.0, 2.0, 3.0], 2, 2, crate::structure::matrix::Shape::Row);
+        let mean = vec![1.0, -1.0];
+        let dist = MultivariateNormal::new(mean.clone(), cov.clone());
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let sample = dist.sample(&mut rng);
+
+        // Recompute mean + L*z independently for the same seed to check sample()
+        // is exactly that transform, not just plausible output.
+        let l = cov.cholesky();
+        let mut rng_for_z = StdRng::seed_from_u64(42);
+        let z: Vec<f64> = (0..mean.len()).map(|_| standard_normal(&mut rng_for_z)).collect();
+        let mut expected = mean;
+        for i in 0..z.len() {
+            let mut acc = 0.0;
+            for j in 0..=i {
+                acc += l[(i, j)] * z[j];
+            }
+            expected[i] += acc;
+        }
+
+        for (a, b) in sample.iter().zip(&expected) {
+            assert!((a - b).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn dirichlet_samples_lie_on_the_simplex() {
+        let mut rng = rand::thread_rng();
+        let dist = Dirichlet::new(vec![1.0, 2.0, 3.0]);
+        let sample = dist.sample(&mut rng);
+        assert_eq!(sample.len(), 3);
+        assert!((sample.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn multinomial_counts_sum_to_n() {
+        let mut rng = rand::thread_rng();
+        let dist = Multinomial::new(10, vec![0.2, 0.3, 0.5]);
+        let counts = dist.sample(&mut rng);
+        assert_eq!(counts.iter().sum::<usize>(), 10);
+    }
+
+    #[test]
+    fn multinomial_pmf_of_expected_split_is_largest_among_neighbors() {
+        let dist = Multinomial::new(10, vec![0.5, 0.5]);
+        let center = dist.pmf(&[5, 5]);
+        let neighbor = dist.pmf(&[6, 4]);
+        assert!(center > neighbor);
+    }
+}
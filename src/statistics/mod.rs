@@ -0,0 +1,3 @@
+//! Statistics: summary statistics and probability distributions.
+
+pub mod dist;
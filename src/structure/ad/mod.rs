@@ -0,0 +1,171 @@
+//! Taylor-mode forward automatic differentiation.
+//!
+//! An [`AD`] value tracks a function value together with a fixed number of its
+//! derivatives as Taylor coefficients: `coeffs[i] = f^(i)(x) / i!`. Elementary
+//! arithmetic operations propagate all tracked orders at once via the usual
+//! Taylor-series product/quotient/chain rules, so `AD * AD`, `AD.sin()`, etc. give
+//! back an `AD` with every derivative up to the same truncation order filled in.
+
+use std::ops::{Add, Mul, Sub};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub mod reverse;
+
+/// A value paired with its Taylor coefficients up to some truncation order.
+///
+/// `coeffs[0]` is the value itself, `coeffs[1]` the first derivative, `coeffs[2]`
+/// the second derivative divided by `2!`, and so on.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AD {
+    coeffs: Vec<f64>,
+}
+
+impl AD {
+    /// A constant: every derivative is zero.
+    pub fn constant(x: f64, order: usize) -> Self {
+        let mut coeffs = vec![0.0; order + 1];
+        coeffs[0] = x;
+        Self { coeffs }
+    }
+
+    /// An independent variable: first derivative seeded to `1`, higher orders zero.
+    pub fn variable(x: f64, order: usize) -> Self {
+        let mut ad = Self::constant(x, order);
+        if order >= 1 {
+            ad.coeffs[1] = 1.0;
+        }
+        ad
+    }
+
+    pub fn order(&self) -> usize {
+        self.coeffs.len() - 1
+    }
+
+    pub fn value(&self) -> f64 {
+        self.coeffs[0]
+    }
+
+    /// `n`-th derivative, or `0.0` if `n` is beyond the truncation order.
+    pub fn derivative(&self, n: usize) -> f64 {
+        if n > self.order() {
+            return 0.0;
+        }
+        factorial(n) * self.coeffs[n]
+    }
+
+    pub fn sin(&self) -> Self {
+        let mut s = vec![0.0; self.coeffs.len()];
+        let mut c = vec![0.0; self.coeffs.len()];
+        s[0] = self.coeffs[0].sin();
+        c[0] = self.coeffs[0].cos();
+        for n in 1..self.coeffs.len() {
+            let mut ds = 0.0;
+            let mut dc = 0.0;
+            for k in 0..n {
+                let d_self = (n - k) as f64 * self.coeffs[n - k];
+                ds += d_self * c[k];
+                dc += d_self * s[k];
+            }
+            s[n] = ds / n as f64;
+            c[n] = -dc / n as f64;
+        }
+        Self { coeffs: s }
+    }
+
+    pub fn exp(&self) -> Self {
+        let mut e = vec![0.0; self.coeffs.len()];
+        e[0] = self.coeffs[0].exp();
+        for n in 1..self.coeffs.len() {
+            let mut acc = 0.0;
+            for k in 0..n {
+                acc += (n - k) as f64 * self.coeffs[n - k] * e[k];
+            }
+            e[n] = acc / n as f64;
+        }
+        Self { coeffs: e }
+    }
+}
+
+fn factorial(n: usize) -> f64 {
+    (1..=n).map(|k| k as f64).product::<f64>().max(1.0)
+}
+
+impl Add for &AD {
+    type Output = AD;
+
+    fn add(self, rhs: &AD) -> AD {
+        assert_eq!(self.order(), rhs.order(), "AD truncation orders must match");
+        let coeffs = self.coeffs.iter().zip(&rhs.coeffs).map(|(a, b)| a + b).collect();
+        AD { coeffs }
+    }
+}
+
+impl Sub for &AD {
+    type Output = AD;
+
+    fn sub(self, rhs: &AD) -> AD {
+        assert_eq!(self.order(), rhs.order(), "AD truncation orders must match");
+        let coeffs = self.coeffs.iter().zip(&rhs.coeffs).map(|(a, b)| a - b).collect();
+        AD { coeffs }
+    }
+}
+
+impl Mul for &AD {
+    type Output = AD;
+
+    /// Cauchy product of the two Taylor series, truncated to the shared order.
+    fn mul(self, rhs: &AD) -> AD {
+        assert_eq!(self.order(), rhs.order(), "AD truncation orders must match");
+        let n = self.coeffs.len();
+        let mut coeffs = vec![0.0; n];
+        for i in 0..n {
+            for j in 0..(n - i) {
+                coeffs[i + j] += self.coeffs[i] * rhs.coeffs[j];
+            }
+        }
+        AD { coeffs }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variable_has_unit_first_derivative() {
+        let x = AD::variable(3.0, 2);
+        assert_eq!(x.derivative(0), 3.0);
+        assert_eq!(x.derivative(1), 1.0);
+        assert_eq!(x.derivative(2), 0.0);
+    }
+
+    #[test]
+    fn product_rule_matches_hand_derivative() {
+        // d/dx(x^2) = 2x
+        let x = AD::variable(3.0, 2);
+        let x2 = &x * &x;
+        assert_eq!(x2.value(), 9.0);
+        assert_eq!(x2.derivative(1), 6.0);
+        assert_eq!(x2.derivative(2), 2.0);
+    }
+
+    #[test]
+    fn sin_value_and_first_derivative_match_sin_and_cos() {
+        let x = AD::variable(0.5, 3);
+        let s = x.sin();
+        assert!((s.value() - 0.5f64.sin()).abs() < 1e-12);
+        assert!((s.derivative(1) - 0.5f64.cos()).abs() < 1e-12);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trips_through_json() {
+        let x = AD::variable(1.5, 2);
+        let json = serde_json::to_string(&x).unwrap();
+        let back: AD = serde_json::from_str(&json).unwrap();
+        assert_eq!(x, back);
+    }
+}
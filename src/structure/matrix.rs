@@ -0,0 +1,327 @@
+//! Dense matrix type and the usual linear-algebra operations on it.
+//!
+//! `Matrix` stores its elements in a single flat buffer plus a `shape` tag that says
+//! whether that buffer should be read out row-major or column-major. `transpose`
+//! copies the elements into a new row-major matrix; it does not flip the tag.
+
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Memory layout of a [`Matrix`]'s backing buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Shape {
+    Row,
+    Col,
+}
+
+/// A dense, real-valued matrix.
+///
+/// Indexing is always `(row, col)` regardless of `shape`; `shape` only affects how
+/// `data` is laid out in memory.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matrix {
+    data: Vec<f64>,
+    row: usize,
+    col: usize,
+    shape: Shape,
+}
+
+impl Matrix {
+    /// Build a matrix from a flat buffer, interpreted according to `shape`.
+    ///
+    /// # Panics
+    /// Panics if `data.len() != row * col`.
+    pub fn new(data: Vec<f64>, row: usize, col: usize, shape: Shape) -> Self {
+        assert_eq!(data.len(), row * col, "data length does not match row * col");
+        Self { data, row, col, shape }
+    }
+
+    pub fn zeros(row: usize, col: usize) -> Self {
+        Self::new(vec![0.0; row * col], row, col, Shape::Row)
+    }
+
+    pub fn eye(n: usize) -> Self {
+        let mut m = Self::zeros(n, n);
+        for i in 0..n {
+            m[(i, i)] = 1.0;
+        }
+        m
+    }
+
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    pub fn col(&self) -> usize {
+        self.col
+    }
+
+    pub fn shape(&self) -> Shape {
+        self.shape
+    }
+
+    /// Row-major view of the matrix data, regardless of internal `shape`.
+    pub fn data(&self) -> Vec<f64> {
+        match self.shape {
+            Shape::Row => self.data.clone(),
+            Shape::Col => {
+                let mut out = vec![0.0; self.data.len()];
+                for i in 0..self.row {
+                    for j in 0..self.col {
+                        out[i * self.col + j] = self.data[j * self.row + i];
+                    }
+                }
+                out
+            }
+        }
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut out = Self::zeros(self.col, self.row);
+        for i in 0..self.row {
+            for j in 0..self.col {
+                out[(j, i)] = self[(i, j)];
+            }
+        }
+        out
+    }
+
+    /// Determinant via LU decomposition with partial pivoting. `O(n^3)`.
+    pub fn det(&self) -> f64 {
+        assert_eq!(self.row, self.col, "det is only defined for square matrices");
+        let (lu, _, num_swaps) = self.lu();
+        let n = self.row;
+        let mut d = if num_swaps % 2 == 0 { 1.0 } else { -1.0 };
+        for i in 0..n {
+            d *= lu[(i, i)];
+        }
+        d
+    }
+
+    /// Inverse via LU decomposition, solving `A x_i = e_i` for each column `e_i`.
+    pub fn inv(&self) -> Self {
+        assert_eq!(self.row, self.col, "inv is only defined for square matrices");
+        let n = self.row;
+        let (lu, perm, _) = self.lu();
+        let mut inv = Self::zeros(n, n);
+
+        for col in 0..n {
+            let mut b = vec![0.0; n];
+            b[col] = 1.0;
+            let b: Vec<f64> = perm.iter().map(|&p| b[p]).collect();
+
+            // Forward substitution (L has unit diagonal).
+            let mut y = vec![0.0; n];
+            for i in 0..n {
+                let mut s = b[i];
+                for k in 0..i {
+                    s -= lu[(i, k)] * y[k];
+                }
+                y[i] = s;
+            }
+
+            // Back substitution.
+            let mut x = vec![0.0; n];
+            for i in (0..n).rev() {
+                let mut s = y[i];
+                for k in (i + 1)..n {
+                    s -= lu[(i, k)] * x[k];
+                }
+                x[i] = s / lu[(i, i)];
+            }
+
+            for row in 0..n {
+                inv[(row, col)] = x[row];
+            }
+        }
+
+        inv
+    }
+
+    /// In-place-style LU decomposition with partial pivoting.
+    ///
+    /// Returns `(lu, perm, num_swaps)` where `lu` packs `L` (unit lower, diagonal
+    /// implicit) and `U` (upper) into one matrix, `perm` is the row permutation applied
+    /// to the original rows, and `num_swaps` is the number of row swaps (used for the
+    /// sign of the determinant).
+    fn lu(&self) -> (Self, Vec<usize>, usize) {
+        assert_eq!(self.row, self.col, "LU decomposition requires a square matrix");
+        let n = self.row;
+        let mut lu = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut num_swaps = 0;
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&a, &b| lu[(a, k)].abs().partial_cmp(&lu[(b, k)].abs()).unwrap())
+                .unwrap();
+            if pivot != k {
+                for j in 0..n {
+                    let tmp = lu[(k, j)];
+                    lu[(k, j)] = lu[(pivot, j)];
+                    lu[(pivot, j)] = tmp;
+                }
+                perm.swap(k, pivot);
+                num_swaps += 1;
+            }
+
+            for i in (k + 1)..n {
+                let factor = lu[(i, k)] / lu[(k, k)];
+                lu[(i, k)] = factor;
+                for j in (k + 1)..n {
+                    lu[(i, j)] -= factor * lu[(k, j)];
+                }
+            }
+        }
+
+        (lu, perm, num_swaps)
+    }
+
+    /// Cholesky decomposition `A = L L^T` for symmetric positive-definite `A`.
+    ///
+    /// Returns the lower-triangular factor `L`. Gated behind the `O3` feature flag
+    /// alongside `QR`/`SVD`, for a consistent story about where the more advanced
+    /// decompositions live, even though this one is a plain triangular elimination
+    /// rather than a LAPACK call.
+    #[cfg(feature = "O3")]
+    pub fn cholesky(&self) -> Self {
+        assert_eq!(self.row, self.col, "Cholesky decomposition requires a square matrix");
+        let n = self.row;
+        let mut l = Self::zeros(n, n);
+
+        for i in 0..n {
+            for j in 0..=i {
+                let mut sum = self[(i, j)];
+                for k in 0..j {
+                    sum -= l[(i, k)] * l[(j, k)];
+                }
+                if i == j {
+                    l[(i, j)] = sum.sqrt();
+                } else {
+                    l[(i, j)] = sum / l[(j, j)];
+                }
+            }
+        }
+
+        l
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    fn index(&self, (r, c): (usize, usize)) -> &f64 {
+        match self.shape {
+            Shape::Row => &self.data[r * self.col + c],
+            Shape::Col => &self.data[c * self.row + r],
+        }
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut f64 {
+        match self.shape {
+            Shape::Row => &mut self.data[r * self.col + c],
+            Shape::Col => &mut self.data[c * self.row + r],
+        }
+    }
+}
+
+impl Add for &Matrix {
+    type Output = Matrix;
+
+    fn add(self, rhs: &Matrix) -> Matrix {
+        assert_eq!((self.row, self.col), (rhs.row, rhs.col), "matrix dimensions must match");
+        let mut out = Matrix::zeros(self.row, self.col);
+        for i in 0..self.row {
+            for j in 0..self.col {
+                out[(i, j)] = self[(i, j)] + rhs[(i, j)];
+            }
+        }
+        out
+    }
+}
+
+impl Sub for &Matrix {
+    type Output = Matrix;
+
+    fn sub(self, rhs: &Matrix) -> Matrix {
+        assert_eq!((self.row, self.col), (rhs.row, rhs.col), "matrix dimensions must match");
+        let mut out = Matrix::zeros(self.row, self.col);
+        for i in 0..self.row {
+            for j in 0..self.col {
+                out[(i, j)] = self[(i, j)] - rhs[(i, j)];
+            }
+        }
+        out
+    }
+}
+
+impl Mul for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: &Matrix) -> Matrix {
+        assert_eq!(self.col, rhs.row, "inner matrix dimensions must match for multiplication");
+        let mut out = Matrix::zeros(self.row, rhs.col);
+        for i in 0..self.row {
+            for k in 0..self.col {
+                let a = self[(i, k)];
+                if a == 0.0 {
+                    continue;
+                }
+                for j in 0..rhs.col {
+                    out[(i, j)] += a * rhs[(k, j)];
+                }
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverse_round_trips_to_identity() {
+        let m = Matrix::new(vec![4.0, 3.0, 6.0, 3.0], 2, 2, Shape::Row);
+        let prod = &m * &m.inv();
+        for i in 0..2 {
+            for j in 0..2 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((prod[(i, j)] - expected).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn det_of_identity_is_one() {
+        assert_eq!(Matrix::eye(4).det(), 1.0);
+    }
+
+    #[cfg(feature = "O3")]
+    #[test]
+    fn cholesky_reconstructs_spd_matrix() {
+        let m = Matrix::new(vec![4.0, 2.0, 2.0, 3.0], 2, 2, Shape::Row);
+        let l = m.cholesky();
+        let reconstructed = &l * &l.transpose();
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((reconstructed[(i, j)] - m[(i, j)]).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trips_through_json() {
+        let m = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], 2, 2, Shape::Row);
+        let json = serde_json::to_string(&m).unwrap();
+        let back: Matrix = serde_json::from_str(&json).unwrap();
+        assert_eq!(m, back);
+    }
+}
@@ -0,0 +1,6 @@
+//! Core data structures: matrices, polynomials & automatic differentiation values.
+
+pub mod ad;
+pub mod matrix;
+pub mod polynomial;
+pub mod sparse;
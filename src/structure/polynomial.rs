@@ -0,0 +1,135 @@
+//! Dense univariate polynomials over `f64`.
+
+use std::ops::{Add, Mul, Sub};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A polynomial `c[0] + c[1] x + c[2] x^2 + ...`, stored by ascending degree.
+///
+/// Trailing (highest-degree) zero coefficients are not trimmed automatically, so that
+/// round-tripping through [`Serialize`]/[`Deserialize`] preserves the exact coefficient
+/// vector the caller built.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Polynomial {
+    coef: Vec<f64>,
+}
+
+impl Polynomial {
+    /// `coef[i]` is the coefficient of `x^i`.
+    pub fn new(coef: Vec<f64>) -> Self {
+        Self { coef }
+    }
+
+    pub fn degree(&self) -> usize {
+        self.coef.len().saturating_sub(1)
+    }
+
+    pub fn coef(&self) -> &[f64] {
+        &self.coef
+    }
+
+    pub fn eval(&self, x: f64) -> f64 {
+        // Horner's method.
+        self.coef.iter().rev().fold(0.0, |acc, &c| acc * x + c)
+    }
+
+    pub fn derivative(&self) -> Self {
+        if self.coef.len() <= 1 {
+            return Self::new(vec![0.0]);
+        }
+        let coef = self
+            .coef
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, &c)| i as f64 * c)
+            .collect();
+        Self::new(coef)
+    }
+}
+
+fn zip_pad(a: &[f64], b: &[f64]) -> Vec<(f64, f64)> {
+    let n = a.len().max(b.len());
+    (0..n)
+        .map(|i| (a.get(i).copied().unwrap_or(0.0), b.get(i).copied().unwrap_or(0.0)))
+        .collect()
+}
+
+impl Add for &Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, rhs: &Polynomial) -> Polynomial {
+        Polynomial::new(zip_pad(&self.coef, &rhs.coef).into_iter().map(|(a, b)| a + b).collect())
+    }
+}
+
+impl Sub for &Polynomial {
+    type Output = Polynomial;
+
+    fn sub(self, rhs: &Polynomial) -> Polynomial {
+        Polynomial::new(zip_pad(&self.coef, &rhs.coef).into_iter().map(|(a, b)| a - b).collect())
+    }
+}
+
+impl Mul for &Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: &Polynomial) -> Polynomial {
+        if self.coef.is_empty() || rhs.coef.is_empty() {
+            return Polynomial::new(vec![]);
+        }
+        let mut coef = vec![0.0; self.coef.len() + rhs.coef.len() - 1];
+        for (i, &a) in self.coef.iter().enumerate() {
+            for (j, &b) in rhs.coef.iter().enumerate() {
+                coef[i + j] += a * b;
+            }
+        }
+        Polynomial::new(coef)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eval_matches_direct_computation() {
+        // 1 + 2x + 3x^2 at x = 2 -> 1 + 4 + 12 = 17
+        let p = Polynomial::new(vec![1.0, 2.0, 3.0]);
+        assert_eq!(p.eval(2.0), 17.0);
+    }
+
+    #[test]
+    fn derivative_of_quadratic() {
+        // d/dx(1 + 2x + 3x^2) = 2 + 6x
+        let p = Polynomial::new(vec![1.0, 2.0, 3.0]);
+        assert_eq!(p.derivative(), Polynomial::new(vec![2.0, 6.0]));
+    }
+
+    #[test]
+    fn multiplies_like_convolution() {
+        // (1 + x)(1 - x) = 1 - x^2
+        let a = Polynomial::new(vec![1.0, 1.0]);
+        let b = Polynomial::new(vec![1.0, -1.0]);
+        assert_eq!(&a * &b, Polynomial::new(vec![1.0, 0.0, -1.0]));
+    }
+
+    #[test]
+    fn multiplying_by_empty_polynomial_gives_empty_polynomial() {
+        let empty = Polynomial::new(vec![]);
+        let a = Polynomial::new(vec![1.0, 2.0]);
+        assert_eq!(&empty * &a, Polynomial::new(vec![]));
+        assert_eq!(&empty * &empty, Polynomial::new(vec![]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trips_through_json() {
+        let p = Polynomial::new(vec![1.0, -2.0, 0.5]);
+        let json = serde_json::to_string(&p).unwrap();
+        let back: Polynomial = serde_json::from_str(&json).unwrap();
+        assert_eq!(p, back);
+    }
+}
@@ -0,0 +1,472 @@
+//! Sparse matrix storage (CSR/CSC) and iterative solvers for the large, mostly-zero
+//! systems that show up in finite-difference/finite-element discretizations, where
+//! the dense [`Matrix`](crate::structure::matrix::Matrix) is infeasible to even store.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::structure::matrix::Matrix;
+
+/// Errors from sparse construction or iterative solves.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SparseError {
+    /// Row/col dimensions didn't agree with the number of stored entries.
+    DimensionMismatch,
+    /// An iterative solver did not converge within its iteration budget.
+    DidNotConverge { iterations: usize, residual: f64 },
+}
+
+impl std::fmt::Display for SparseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SparseError::DimensionMismatch => write!(f, "sparse matrix dimensions do not match its entries"),
+            SparseError::DidNotConverge { iterations, residual } => {
+                write!(f, "solver did not converge after {iterations} iterations (residual {residual:e})")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SparseError {}
+
+/// Compressed Sparse Row storage: `col_idx`/`values` for row `i` live in
+/// `row_ptr[i]..row_ptr[i + 1]`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CsrMatrix {
+    row_ptr: Vec<usize>,
+    col_idx: Vec<usize>,
+    values: Vec<f64>,
+    row: usize,
+    col: usize,
+}
+
+/// Compressed Sparse Column storage, the transpose layout of [`CsrMatrix`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CscMatrix {
+    col_ptr: Vec<usize>,
+    row_idx: Vec<usize>,
+    values: Vec<f64>,
+    row: usize,
+    col: usize,
+}
+
+impl CsrMatrix {
+    /// Build directly from CSR arrays.
+    ///
+    /// # Panics
+    /// Panics if `row_ptr.len() != row + 1` or `col_idx.len() != values.len()`.
+    pub fn new(row_ptr: Vec<usize>, col_idx: Vec<usize>, values: Vec<f64>, row: usize, col: usize) -> Self {
+        assert_eq!(row_ptr.len(), row + 1, "row_ptr must have row + 1 entries");
+        assert_eq!(col_idx.len(), values.len(), "col_idx and values must be the same length");
+        Self { row_ptr, col_idx, values, row, col }
+    }
+
+    /// Build from coordinate `(row, col, value)` triplets, summing duplicates.
+    pub fn from_triplets(row: usize, col: usize, triplets: &[(usize, usize, f64)]) -> Self {
+        let mut rows: Vec<Vec<(usize, f64)>> = vec![Vec::new(); row];
+        for &(r, c, v) in triplets {
+            rows[r].push((c, v));
+        }
+
+        let mut row_ptr = vec![0; row + 1];
+        let mut col_idx = Vec::new();
+        let mut values = Vec::new();
+        for (i, entries) in rows.into_iter().enumerate() {
+            let mut merged: Vec<(usize, f64)> = Vec::new();
+            for (c, v) in entries {
+                if let Some(existing) = merged.iter_mut().find(|(ec, _)| *ec == c) {
+                    existing.1 += v;
+                } else {
+                    merged.push((c, v));
+                }
+            }
+            merged.sort_by_key(|&(c, _)| c);
+            for (c, v) in merged {
+                col_idx.push(c);
+                values.push(v);
+            }
+            row_ptr[i + 1] = col_idx.len();
+        }
+
+        Self { row_ptr, col_idx, values, row, col }
+    }
+
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    pub fn col(&self) -> usize {
+        self.col
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn from_dense(m: &Matrix) -> Self {
+        let mut triplets = Vec::new();
+        for i in 0..m.row() {
+            for j in 0..m.col() {
+                let v = m[(i, j)];
+                if v != 0.0 {
+                    triplets.push((i, j, v));
+                }
+            }
+        }
+        Self::from_triplets(m.row(), m.col(), &triplets)
+    }
+
+    pub fn to_dense(&self) -> Matrix {
+        let mut m = Matrix::zeros(self.row, self.col);
+        for i in 0..self.row {
+            for idx in self.row_ptr[i]..self.row_ptr[i + 1] {
+                m[(i, self.col_idx[idx])] = self.values[idx];
+            }
+        }
+        m
+    }
+
+    /// `y = A x`.
+    pub fn matvec(&self, x: &[f64]) -> Vec<f64> {
+        assert_eq!(x.len(), self.col, "vector length must match matrix column count");
+        let mut y = vec![0.0; self.row];
+        for i in 0..self.row {
+            let mut acc = 0.0;
+            for idx in self.row_ptr[i]..self.row_ptr[i + 1] {
+                acc += self.values[idx] * x[self.col_idx[idx]];
+            }
+            y[i] = acc;
+        }
+        y
+    }
+
+    /// `C = A B` for two CSR matrices, computed row by row.
+    pub fn matmul(&self, rhs: &CsrMatrix) -> CsrMatrix {
+        assert_eq!(self.col, rhs.row, "inner dimensions must match for sparse multiplication");
+        let mut triplets = Vec::new();
+        for i in 0..self.row {
+            let mut acc = vec![0.0; rhs.col];
+            for idx in self.row_ptr[i]..self.row_ptr[i + 1] {
+                let k = self.col_idx[idx];
+                let a_ik = self.values[idx];
+                for jdx in rhs.row_ptr[k]..rhs.row_ptr[k + 1] {
+                    acc[rhs.col_idx[jdx]] += a_ik * rhs.values[jdx];
+                }
+            }
+            for (j, &v) in acc.iter().enumerate() {
+                if v != 0.0 {
+                    triplets.push((i, j, v));
+                }
+            }
+        }
+        CsrMatrix::from_triplets(self.row, rhs.col, &triplets)
+    }
+
+    fn diagonal(&self) -> Vec<f64> {
+        let mut d = vec![0.0; self.row.min(self.col)];
+        for i in 0..d.len() {
+            for idx in self.row_ptr[i]..self.row_ptr[i + 1] {
+                if self.col_idx[idx] == i {
+                    d[i] = self.values[idx];
+                }
+            }
+        }
+        d
+    }
+}
+
+impl CscMatrix {
+    pub fn new(col_ptr: Vec<usize>, row_idx: Vec<usize>, values: Vec<f64>, row: usize, col: usize) -> Self {
+        assert_eq!(col_ptr.len(), col + 1, "col_ptr must have col + 1 entries");
+        assert_eq!(row_idx.len(), values.len(), "row_idx and values must be the same length");
+        Self { col_ptr, row_idx, values, row, col }
+    }
+
+    pub fn from_dense(m: &Matrix) -> Self {
+        CsrMatrix::from_dense(m).into()
+    }
+
+    pub fn to_dense(&self) -> Matrix {
+        let mut m = Matrix::zeros(self.row, self.col);
+        for j in 0..self.col {
+            for idx in self.col_ptr[j]..self.col_ptr[j + 1] {
+                m[(self.row_idx[idx], j)] = self.values[idx];
+            }
+        }
+        m
+    }
+
+    /// `y = A x`.
+    pub fn matvec(&self, x: &[f64]) -> Vec<f64> {
+        assert_eq!(x.len(), self.col, "vector length must match matrix column count");
+        let mut y = vec![0.0; self.row];
+        for j in 0..self.col {
+            let xj = x[j];
+            if xj == 0.0 {
+                continue;
+            }
+            for idx in self.col_ptr[j]..self.col_ptr[j + 1] {
+                y[self.row_idx[idx]] += self.values[idx] * xj;
+            }
+        }
+        y
+    }
+}
+
+impl From<CsrMatrix> for CscMatrix {
+    fn from(csr: CsrMatrix) -> Self {
+        let mut col_counts = vec![0usize; csr.col + 1];
+        for &c in &csr.col_idx {
+            col_counts[c + 1] += 1;
+        }
+        for j in 0..csr.col {
+            col_counts[j + 1] += col_counts[j];
+        }
+        let col_ptr = col_counts.clone();
+
+        let mut row_idx = vec![0usize; csr.values.len()];
+        let mut values = vec![0.0; csr.values.len()];
+        let mut cursor = col_counts;
+        for i in 0..csr.row {
+            for idx in csr.row_ptr[i]..csr.row_ptr[i + 1] {
+                let c = csr.col_idx[idx];
+                let dest = cursor[c];
+                row_idx[dest] = i;
+                values[dest] = csr.values[idx];
+                cursor[c] += 1;
+            }
+        }
+
+        CscMatrix { col_ptr, row_idx, values, row: csr.row, col: csr.col }
+    }
+}
+
+/// Applies an approximate inverse of `A` to accelerate Krylov-subspace convergence.
+pub trait Preconditioner {
+    fn apply(&self, r: &[f64]) -> Vec<f64>;
+}
+
+/// Diagonal (Jacobi) preconditioner: `M^-1 = diag(1 / a_ii)`.
+pub struct JacobiPreconditioner {
+    inv_diag: Vec<f64>,
+}
+
+impl JacobiPreconditioner {
+    pub fn new(a: &CsrMatrix) -> Self {
+        let inv_diag = a.diagonal().into_iter().map(|d| if d != 0.0 { 1.0 / d } else { 1.0 }).collect();
+        Self { inv_diag }
+    }
+}
+
+impl Preconditioner for JacobiPreconditioner {
+    fn apply(&self, r: &[f64]) -> Vec<f64> {
+        r.iter().zip(&self.inv_diag).map(|(ri, di)| ri * di).collect()
+    }
+}
+
+/// No-op preconditioner, useful as a baseline to compare convergence against.
+pub struct IdentityPreconditioner;
+
+impl Preconditioner for IdentityPreconditioner {
+    fn apply(&self, r: &[f64]) -> Vec<f64> {
+        r.to_vec()
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn axpy(alpha: f64, x: &[f64], y: &[f64]) -> Vec<f64> {
+    x.iter().zip(y).map(|(xi, yi)| alpha * xi + yi).collect()
+}
+
+/// Preconditioned Conjugate Gradient for symmetric positive-definite `A x = b`.
+pub fn conjugate_gradient(
+    a: &CsrMatrix,
+    b: &[f64],
+    precond: &impl Preconditioner,
+    tol: f64,
+    max_iter: usize,
+) -> Result<Vec<f64>, SparseError> {
+    let n = b.len();
+    let mut x = vec![0.0; n];
+    let mut r = b.to_vec();
+    let b_norm = dot(b, b).sqrt().max(1e-300);
+
+    let mut z = precond.apply(&r);
+    let mut p = z.clone();
+    let mut rz_old = dot(&r, &z);
+
+    for iter in 0..max_iter {
+        let residual = dot(&r, &r).sqrt() / b_norm;
+        if residual < tol {
+            return Ok(x);
+        }
+
+        let ap = a.matvec(&p);
+        let alpha = rz_old / dot(&p, &ap);
+        x = axpy(alpha, &p, &x);
+        r = axpy(-alpha, &ap, &r);
+
+        z = precond.apply(&r);
+        let rz_new = dot(&r, &z);
+        let beta = rz_new / rz_old;
+        p = axpy(beta, &p, &z);
+        rz_old = rz_new;
+
+        if iter == max_iter - 1 {
+            let residual = dot(&r, &r).sqrt() / b_norm;
+            if residual < tol {
+                return Ok(x);
+            }
+            return Err(SparseError::DidNotConverge { iterations: max_iter, residual });
+        }
+    }
+
+    Ok(x)
+}
+
+/// Preconditioned BiCGSTAB for general (non-symmetric) `A x = b`.
+pub fn bicgstab(
+    a: &CsrMatrix,
+    b: &[f64],
+    precond: &impl Preconditioner,
+    tol: f64,
+    max_iter: usize,
+) -> Result<Vec<f64>, SparseError> {
+    let n = b.len();
+    let mut x = vec![0.0; n];
+    let mut r = b.to_vec();
+    let r_hat = r.clone();
+    let b_norm = dot(b, b).sqrt().max(1e-300);
+
+    let mut rho_old = 1.0;
+    let mut alpha = 1.0;
+    let mut omega = 1.0;
+    let mut v = vec![0.0; n];
+    let mut p = vec![0.0; n];
+
+    for iter in 0..max_iter {
+        let residual = dot(&r, &r).sqrt() / b_norm;
+        if residual < tol {
+            return Ok(x);
+        }
+
+        let rho_new = dot(&r_hat, &r);
+        if rho_new == 0.0 {
+            return Err(SparseError::DidNotConverge { iterations: iter, residual });
+        }
+        let beta = (rho_new / rho_old) * (alpha / omega);
+        p = axpy(beta, &axpy(-omega, &v, &p), &r);
+        let p_hat = precond.apply(&p);
+        v = a.matvec(&p_hat);
+
+        alpha = rho_new / dot(&r_hat, &v);
+        let s = axpy(-alpha, &v, &r);
+        if dot(&s, &s).sqrt() / b_norm < tol {
+            x = axpy(alpha, &p_hat, &x);
+            return Ok(x);
+        }
+
+        let s_hat = precond.apply(&s);
+        let t = a.matvec(&s_hat);
+        omega = dot(&t, &s) / dot(&t, &t);
+
+        x = axpy(alpha, &p_hat, &x);
+        x = axpy(omega, &s_hat, &x);
+        r = axpy(-omega, &t, &s);
+        rho_old = rho_new;
+
+        if iter == max_iter - 1 {
+            let residual = dot(&r, &r).sqrt() / b_norm;
+            if residual < tol {
+                return Ok(x);
+            }
+            return Err(SparseError::DidNotConverge { iterations: max_iter, residual });
+        }
+    }
+
+    Ok(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structure::matrix::Shape;
+
+    fn spd_3x3() -> Matrix {
+        // [[4,1,0],[1,3,1],[0,1,2]] - symmetric positive definite
+        Matrix::new(vec![4.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0], 3, 3, Shape::Row)
+    }
+
+    #[test]
+    fn csr_round_trips_through_dense() {
+        let m = spd_3x3();
+        let csr = CsrMatrix::from_dense(&m);
+        assert_eq!(csr.to_dense(), m);
+    }
+
+    #[test]
+    fn csc_round_trips_through_dense() {
+        let m = spd_3x3();
+        let csc = CscMatrix::from_dense(&m);
+        assert_eq!(csc.to_dense(), m);
+    }
+
+    #[test]
+    fn matvec_matches_dense() {
+        let m = spd_3x3();
+        let csr = CsrMatrix::from_dense(&m);
+        let x = vec![1.0, 2.0, 3.0];
+        assert_eq!(csr.matvec(&x), vec![6.0, 10.0, 8.0]);
+    }
+
+    #[test]
+    fn conjugate_gradient_solves_spd_system() {
+        let m = spd_3x3();
+        let csr = CsrMatrix::from_dense(&m);
+        let b = vec![1.0, 2.0, 3.0];
+        let precond = JacobiPreconditioner::new(&csr);
+        let x = conjugate_gradient(&csr, &b, &precond, 1e-10, 100).unwrap();
+
+        let reconstructed = csr.matvec(&x);
+        for (a, b) in reconstructed.iter().zip(&b) {
+            assert!((a - b).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn conjugate_gradient_succeeds_when_converging_on_the_last_allowed_iteration() {
+        // 2x = 4 converges in exactly one CG iteration, so max_iter = 1 should still
+        // report success instead of spuriously erroring on the final check.
+        let csr = CsrMatrix::from_dense(&Matrix::new(vec![2.0], 1, 1, Shape::Row));
+        let b = vec![4.0];
+        let x = conjugate_gradient(&csr, &b, &IdentityPreconditioner, 1e-10, 1).unwrap();
+        assert!((x[0] - 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn bicgstab_succeeds_when_converging_on_the_last_allowed_iteration() {
+        let csr = CsrMatrix::from_dense(&Matrix::new(vec![2.0], 1, 1, Shape::Row));
+        let b = vec![4.0];
+        let x = bicgstab(&csr, &b, &IdentityPreconditioner, 1e-10, 1).unwrap();
+        assert!((x[0] - 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn bicgstab_solves_nonsymmetric_system() {
+        // [[4,1,1],[0,3,1],[1,0,2]] - diagonally dominant, non-symmetric
+        let m = Matrix::new(vec![4.0, 1.0, 1.0, 0.0, 3.0, 1.0, 1.0, 0.0, 2.0], 3, 3, Shape::Row);
+        let csr = CsrMatrix::from_dense(&m);
+        let b = vec![6.0, 4.0, 3.0];
+        let x = bicgstab(&csr, &b, &IdentityPreconditioner, 1e-10, 100).unwrap();
+
+        let reconstructed = csr.matvec(&x);
+        for (a, b) in reconstructed.iter().zip(&b) {
+            assert!((a - b).abs() < 1e-7);
+        }
+    }
+}